@@ -0,0 +1,61 @@
+use std::fmt;
+
+/// Error type shared across `columnq`'s table loaders.
+#[derive(Debug)]
+pub enum ColumnQError {
+    Io(std::io::Error),
+    Arrow(datafusion::arrow::error::ArrowError),
+    DataFusion(datafusion::error::DataFusionError),
+    ObjectStore(object_store::Error),
+    /// A table's schema, or a value meant to fit it, doesn't make sense --
+    /// e.g. a Hive partition column missing from a path, or a value that
+    /// can't be coerced to its declared type.
+    InvalidTableSchema(String),
+}
+
+impl fmt::Display for ColumnQError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColumnQError::Io(e) => write!(f, "IO error: {e}"),
+            ColumnQError::Arrow(e) => write!(f, "Arrow error: {e}"),
+            ColumnQError::DataFusion(e) => write!(f, "DataFusion error: {e}"),
+            ColumnQError::ObjectStore(e) => write!(f, "object store error: {e}"),
+            ColumnQError::InvalidTableSchema(msg) => write!(f, "invalid table schema: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for ColumnQError {}
+
+impl From<std::io::Error> for ColumnQError {
+    fn from(e: std::io::Error) -> Self {
+        ColumnQError::Io(e)
+    }
+}
+
+impl From<datafusion::arrow::error::ArrowError> for ColumnQError {
+    fn from(e: datafusion::arrow::error::ArrowError) -> Self {
+        ColumnQError::Arrow(e)
+    }
+}
+
+impl From<datafusion::error::DataFusionError> for ColumnQError {
+    fn from(e: datafusion::error::DataFusionError) -> Self {
+        ColumnQError::DataFusion(e)
+    }
+}
+
+impl From<object_store::Error> for ColumnQError {
+    fn from(e: object_store::Error) -> Self {
+        ColumnQError::ObjectStore(e)
+    }
+}
+
+impl From<ColumnQError> for datafusion::error::DataFusionError {
+    fn from(e: ColumnQError) -> Self {
+        match e {
+            ColumnQError::DataFusion(e) => e,
+            other => datafusion::error::DataFusionError::External(Box::new(other)),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod error;
+pub mod table;
+
+#[cfg(test)]
+pub(crate) mod test_util;
@@ -1,30 +1,80 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use async_trait::async_trait;
 use datafusion::arrow;
-use datafusion::arrow::datatypes::Schema;
+use datafusion::arrow::datatypes::{DataType, Field, Schema, SchemaRef};
 use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::datasource::TableProvider;
+use datafusion::datasource::TableType;
+use datafusion::error::DataFusionError;
+use datafusion::execution::context::SessionState;
+use datafusion::logical_expr::{BinaryExpr, Expr, Operator};
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use datafusion::physical_plan::{
+    DisplayAs, DisplayFormatType, ExecutionPlan, Partitioning, SendableRecordBatchStream,
+    Statistics,
+};
+use datafusion::scalar::ScalarValue;
+use futures::stream::{self, StreamExt, TryStreamExt};
 use log::debug;
+use object_store::{ObjectMeta, ObjectStore};
 
 use crate::error::ColumnQError;
-use crate::table::TableSource;
+use crate::table::{TableLoadOption, TableSource};
+
+// IPC File format files begin with this magic string (padded to an 8 byte
+// boundary). The streaming format has no such header, so peeking at it is
+// enough to tell the two apart without consuming the reader.
+const ARROW_FILE_MAGIC: &[u8] = b"ARROW1";
+
+/// Reads a full (schema, batches) pair out of a File- or Stream-format IPC
+/// reader, auto-detecting the format from the leading magic bytes.
+fn read_arrow_ipc<R: Read + Seek>(mut r: R) -> Result<(Schema, Vec<RecordBatch>), ColumnQError> {
+    let mut magic_buf = [0u8; 6];
+    r.read_exact(&mut magic_buf)?;
+    r.seek(SeekFrom::Start(0))?;
+
+    if magic_buf == ARROW_FILE_MAGIC {
+        let reader = arrow::ipc::reader::FileReader::try_new(&mut r, None)?;
+        let schema = (*reader.schema()).clone();
+        let batches = reader
+            .into_iter()
+            .map(|batch| Ok(batch?))
+            .collect::<Result<Vec<RecordBatch>, ColumnQError>>()?;
+        Ok((schema, batches))
+    } else {
+        debug!("arrow file magic not found, falling back to IPC stream reader...");
+        let reader = arrow::ipc::reader::StreamReader::try_new(&mut r, None)?;
+        let schema = (*reader.schema()).clone();
+        let batches = reader
+            .into_iter()
+            .map(|batch| Ok(batch?))
+            .collect::<Result<Vec<RecordBatch>, ColumnQError>>()?;
+        Ok((schema, batches))
+    }
+}
 
 pub async fn to_mem_table(
     t: &TableSource,
     dfctx: &datafusion::execution::context::SessionContext,
-) -> Result<datafusion::datasource::MemTable, ColumnQError> {
+) -> Result<Arc<dyn TableProvider>, ColumnQError> {
     debug!("loading arrow table data...");
+
+    if let Some(TableLoadOption::arrow { lazy: true }) = &t.option {
+        return to_listing_table(t).await;
+    }
+
+    if t.partition_columns.is_some() || t.load_concurrency.is_some() {
+        return Ok(Arc::new(to_mem_table_concurrent(t).await?));
+    }
+
     let mut schema_and_partitions = partitions_from_table_source!(
         t,
-        |mut r| {
-            let arrow_file_reader = arrow::ipc::reader::FileReader::try_new(&mut r, None)?;
-            let schema = (*arrow_file_reader.schema()).clone();
-
-            arrow_file_reader
-                .into_iter()
-                .map(|batch| Ok(batch?))
-                .collect::<Result<Vec<RecordBatch>, ColumnQError>>()
-                .map(|batches| (Some(schema), batches))
-        },
+        |mut r| read_arrow_ipc(&mut r).map(|(schema, batches)| (Some(schema), batches)),
         dfctx
     )?;
 
@@ -41,15 +91,824 @@ pub async fn to_mem_table(
         }
     };
 
-    Ok(datafusion::datasource::MemTable::try_new(
+    Ok(Arc::new(datafusion::datasource::MemTable::try_new(
         schema_ref,
         schema_and_partitions
             .into_iter()
             .map(|v| v.1)
             .collect::<Vec<Vec<RecordBatch>>>(),
+    )?))
+}
+
+/// Resolves `t.location()` to an `ObjectStore` and builds a
+/// [`ListingArrowTable`] over everything it lists. This is the dispatch
+/// target for `TableLoadOption::arrow { lazy: true }`; see that provider's
+/// docs for why large or remote sources want it over the eager `MemTable`
+/// path above.
+async fn to_listing_table(t: &TableSource) -> Result<Arc<dyn TableProvider>, ColumnQError> {
+    let object_store = build_object_store(t.location())?;
+    let root = resolve_location_path(t.location());
+    let partitions: Vec<ObjectMeta> = object_store
+        .list(Some(&root))
+        .map_ok(|meta| meta)
+        .try_collect()
+        .await?;
+
+    Ok(Arc::new(
+        ListingArrowTable::try_new(t, object_store, partitions).await?,
+    ))
+}
+
+/// Builds the `ObjectStore` backing `location`, dispatching on URI scheme:
+/// `s3://bucket/key` (AWS S3 and compatible), `gs://bucket/key` /
+/// `gcs://bucket/key` (Google Cloud Storage), `http(s)://host/path`, or a
+/// bare filesystem path (optionally `file://...`) for local disk.
+fn build_object_store(location: &str) -> Result<Arc<dyn ObjectStore>, ColumnQError> {
+    if let Ok(url) = url::Url::parse(location) {
+        match url.scheme() {
+            "s3" => {
+                let bucket = url.host_str().ok_or_else(|| {
+                    ColumnQError::InvalidTableSchema(format!(
+                        "missing S3 bucket in location: {location}"
+                    ))
+                })?;
+                return Ok(Arc::new(
+                    object_store::aws::AmazonS3Builder::from_env()
+                        .with_bucket_name(bucket)
+                        .build()?,
+                ));
+            }
+            "gs" | "gcs" => {
+                let bucket = url.host_str().ok_or_else(|| {
+                    ColumnQError::InvalidTableSchema(format!(
+                        "missing GCS bucket in location: {location}"
+                    ))
+                })?;
+                return Ok(Arc::new(
+                    object_store::gcp::GoogleCloudStorageBuilder::from_env()
+                        .with_bucket_name(bucket)
+                        .build()?,
+                ));
+            }
+            "http" | "https" => {
+                let host = url.host_str().ok_or_else(|| {
+                    ColumnQError::InvalidTableSchema(format!(
+                        "missing host in location: {location}"
+                    ))
+                })?;
+                let root = format!("{}://{host}", url.scheme());
+                return Ok(Arc::new(
+                    object_store::http::HttpBuilder::new().with_url(root).build()?,
+                ));
+            }
+            "file" => {
+                return Ok(Arc::new(
+                    object_store::local::LocalFileSystem::new_with_prefix(url.path())?,
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(Arc::new(object_store::local::LocalFileSystem::new_with_prefix(
+        PathBuf::from(location),
+    )?))
+}
+
+/// Returns the path within `build_object_store(location)`'s store that
+/// `location` refers to. Remote schemes keep whatever comes after the
+/// bucket/host in the URL; the local filesystem variants root the store
+/// directly at `location` (see `build_object_store`), so there's nothing
+/// left to strip and the in-store path is empty.
+fn resolve_location_path(location: &str) -> object_store::path::Path {
+    match url::Url::parse(location) {
+        Ok(url) if matches!(url.scheme(), "s3" | "gs" | "gcs" | "http" | "https") => {
+            object_store::path::Path::from(url.path().trim_start_matches('/'))
+        }
+        _ => object_store::path::Path::from(""),
+    }
+}
+
+/// Loads an arrow table's partitions directly off the local filesystem,
+/// bypassing the `partitions_from_table_source!` macro path above. This is
+/// the path taken whenever `t.partition_columns` (Hive-style directories,
+/// e.g. `date=2020-01-02/region=eu/part.arrow`) or `t.load_concurrency` is
+/// set; plain tables with neither keep using the macro-based path, which is
+/// also local-filesystem only. Unlike that path, this one walks the table
+/// directory recursively.
+///
+/// `t.load_concurrency` (the available parallelism when unset) bounds how
+/// many decode futures `buffer_unordered` holds at once. Each future decodes
+/// its file synchronously when first polled, so the decodes themselves run
+/// one after another on the calling task. Results are reassembled by their
+/// original, path-sorted index so scans stay reproducible.
+async fn to_mem_table_concurrent(
+    t: &TableSource,
+) -> Result<datafusion::datasource::MemTable, ColumnQError> {
+    let partition_columns = t.partition_columns.clone().unwrap_or_default();
+    let concurrency = t.load_concurrency.unwrap_or_else(|| {
+        std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+    });
+
+    let base = PathBuf::from(t.location());
+    let paths = collect_partition_file_paths(&base)?;
+    debug!("loading {} arrow partition(s) with concurrency {concurrency}...", paths.len());
+
+    let mut decoded: Vec<(usize, Result<(Schema, Vec<RecordBatch>), ColumnQError>)> =
+        stream::iter(paths.into_iter().enumerate())
+            .map(|(i, path)| {
+                let partition_columns = partition_columns.clone();
+                async move { (i, decode_partition_file(&path, &partition_columns)) }
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await;
+    decoded.sort_by_key(|(i, _)| *i);
+
+    let mut schemas = Vec::with_capacity(decoded.len());
+    let mut partitions = Vec::with_capacity(decoded.len());
+    for (_, result) in decoded {
+        let (schema, batches) = result?;
+        schemas.push(schema);
+        partitions.push(batches);
+    }
+
+    let schema_ref = match &t.schema {
+        Some(s) => Arc::new(s.into()),
+        None => Arc::new(Schema::try_merge(schemas)?),
+    };
+
+    Ok(datafusion::datasource::MemTable::try_new(
+        schema_ref, partitions,
+    )?)
+}
+
+fn decode_partition_file(
+    path: &Path,
+    partition_columns: &[(String, DataType)],
+) -> Result<(Schema, Vec<RecordBatch>), ColumnQError> {
+    let values = partition_values_from_path(path, partition_columns)?;
+    let file = std::fs::File::open(path)?;
+    let (schema, batches) = read_arrow_ipc(file)?;
+
+    let schema = append_partition_fields(&schema, partition_columns);
+    let batches = batches
+        .iter()
+        .map(|b| append_partition_columns(b, &values))
+        .collect::<Result<Vec<RecordBatch>, ColumnQError>>()?;
+
+    Ok((schema, batches))
+}
+
+/// Recursively collects every regular file under `base`, in a stable,
+/// deterministic order (`base` itself, if it is not a directory).
+fn collect_partition_file_paths(base: &Path) -> Result<Vec<PathBuf>, ColumnQError> {
+    if !base.is_dir() {
+        return Ok(vec![base.to_path_buf()]);
+    }
+
+    let mut paths = vec![];
+    let mut stack = vec![base.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        for entry in std::fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if path.is_dir() {
+                stack.push(path);
+            } else {
+                paths.push(path);
+            }
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// Parses Hive-style `key=value` path segments out of `path`, returning a
+/// coerced `ScalarValue` per declared `partition_columns` entry. Errors if a
+/// declared key is missing from the path.
+fn partition_values_from_path(
+    path: &Path,
+    partition_columns: &[(String, DataType)],
+) -> Result<Vec<(String, ScalarValue)>, ColumnQError> {
+    partition_values_from_path_str(&path.to_string_lossy(), partition_columns)
+}
+
+/// Parses Hive-style `key=value` segments out of a `/`-separated path string
+/// (works for both local filesystem paths and `ObjectStore` locations),
+/// returning a coerced `ScalarValue` per declared `partition_columns` entry.
+fn partition_values_from_path_str(
+    path_str: &str,
+    partition_columns: &[(String, DataType)],
+) -> Result<Vec<(String, ScalarValue)>, ColumnQError> {
+    let mut segments: HashMap<&str, &str> = HashMap::new();
+    for component in path_str.split(['/', '\\']) {
+        if let Some((k, v)) = component.split_once('=') {
+            segments.insert(k, v);
+        }
+    }
+
+    partition_columns
+        .iter()
+        .map(|(name, dtype)| {
+            let raw = segments.get(name.as_str()).ok_or_else(|| {
+                ColumnQError::InvalidTableSchema(format!(
+                    "partition column `{name}` not found in path: {path_str}"
+                ))
+            })?;
+            Ok((name.clone(), coerce_partition_value(raw, dtype)?))
+        })
+        .collect()
+}
+
+/// Coerces a raw path segment value to the declared Arrow type of a
+/// partition column.
+fn coerce_partition_value(raw: &str, dtype: &DataType) -> Result<ScalarValue, ColumnQError> {
+    let err = || {
+        ColumnQError::InvalidTableSchema(format!(
+            "cannot coerce partition value `{raw}` to {dtype:?}"
+        ))
+    };
+
+    Ok(match dtype {
+        DataType::Utf8 => ScalarValue::Utf8(Some(raw.to_string())),
+        DataType::Int32 => ScalarValue::Int32(Some(raw.parse().map_err(|_| err())?)),
+        DataType::Int64 => ScalarValue::Int64(Some(raw.parse().map_err(|_| err())?)),
+        DataType::UInt32 => ScalarValue::UInt32(Some(raw.parse().map_err(|_| err())?)),
+        DataType::UInt64 => ScalarValue::UInt64(Some(raw.parse().map_err(|_| err())?)),
+        DataType::Boolean => ScalarValue::Boolean(Some(raw.parse().map_err(|_| err())?)),
+        other => return Err(ColumnQError::InvalidTableSchema(format!(
+            "unsupported partition column type {other:?}, expected one of Utf8/Int32/Int64/UInt32/UInt64/Boolean"
+        ))),
+    })
+}
+
+fn append_partition_fields(schema: &Schema, partition_columns: &[(String, DataType)]) -> Schema {
+    let mut fields: Vec<Field> = schema.fields().iter().map(|f| f.as_ref().clone()).collect();
+    for (name, dtype) in partition_columns {
+        fields.push(Field::new(name, dtype.clone(), false));
+    }
+    Schema::new(fields)
+}
+
+/// Appends one constant-valued column per entry in `values`, in order, to
+/// `batch`.
+fn append_partition_columns(
+    batch: &RecordBatch,
+    values: &[(String, ScalarValue)],
+) -> Result<RecordBatch, ColumnQError> {
+    let mut fields: Vec<Field> = batch
+        .schema()
+        .fields()
+        .iter()
+        .map(|f| f.as_ref().clone())
+        .collect();
+    let mut columns = batch.columns().to_vec();
+
+    for (name, value) in values {
+        fields.push(Field::new(name, value.data_type(), false));
+        columns.push(value.to_array_of_size(batch.num_rows())?);
+    }
+
+    Ok(RecordBatch::try_new(
+        Arc::new(Schema::new(fields)),
+        columns,
     )?)
 }
 
+/// A `TableProvider` over one or more Arrow IPC partitions resolved through an
+/// `ObjectStore` (local FS, S3, GCS, HTTP, ...). Unlike [`to_mem_table`], which
+/// eagerly decodes every partition into memory up front, this provider only
+/// resolves the table schema at registration time and defers decoding each
+/// partition's `RecordBatch`es until `scan()` actually runs.
+///
+/// This is the path `TableLoadOption::arrow { lazy: true, .. }` maps to, and
+/// is intended for large or remote partition sets where `to_mem_table` would
+/// otherwise require the whole directory to fit in memory before any query
+/// can run.
+pub struct ListingArrowTable {
+    schema: SchemaRef,
+    object_store: Arc<dyn ObjectStore>,
+    /// Base directory new partitions written by `insert_into` are placed
+    /// under, so a later listing of the table directory picks them up.
+    location: object_store::path::Path,
+    partitions: Vec<ObjectMeta>,
+    partition_columns: Vec<(String, DataType)>,
+    /// Parsed Hive partition values for each entry in `partitions`, in the
+    /// same order, used both to materialize the partition columns and to
+    /// prune partitions whose values can't satisfy a pushed-down filter
+    /// without decoding the file at all.
+    partition_values: Vec<Vec<(String, ScalarValue)>>,
+}
+
+impl ListingArrowTable {
+    /// Resolve partitions for `t` through `object_store` and determine the
+    /// merged schema. Partition bytes themselves are not read here; the data
+    /// schema is pulled from each partition's IPC footer/stream header only,
+    /// and any `t.partition_columns` are derived from each partition's path
+    /// and appended on top.
+    pub async fn try_new(
+        t: &TableSource,
+        object_store: Arc<dyn ObjectStore>,
+        partitions: Vec<ObjectMeta>,
+    ) -> Result<Self, ColumnQError> {
+        let partition_columns = t.partition_columns.clone().unwrap_or_default();
+        let partition_values = partitions
+            .iter()
+            .map(|p| partition_values_from_path_str(p.location.as_ref(), &partition_columns))
+            .collect::<Result<Vec<_>, ColumnQError>>()?;
+
+        let schema = match &t.schema {
+            Some(s) => Arc::new(s.into()),
+            None => {
+                debug!(
+                    "inferring lazy arrow table schema from {} partition(s)...",
+                    partitions.len()
+                );
+                let mut schemas = Vec::with_capacity(partitions.len());
+                for p in &partitions {
+                    let schema = read_ipc_schema_ranged(&object_store, p).await?;
+                    schemas.push(append_partition_fields(&schema, &partition_columns));
+                }
+                Arc::new(Schema::try_merge(schemas)?)
+            }
+        };
+
+        Ok(Self {
+            schema,
+            object_store,
+            location: resolve_location_path(t.location()),
+            partitions,
+            partition_columns,
+            partition_values,
+        })
+    }
+
+    /// Returns the indices of partitions that cannot be ruled out by
+    /// `filters`. A filter only prunes partitions when every column it
+    /// references is a partition column, so data-dependent predicates always
+    /// fall through and keep every partition.
+    fn prune_partitions(&self, filters: &[Expr]) -> Vec<usize> {
+        (0..self.partitions.len())
+            .filter(|&i| {
+                filters
+                    .iter()
+                    .all(|f| partition_filter_admits(f, &self.partition_values[i]))
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl TableProvider for ListingArrowTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Base
+    }
+
+    async fn scan(
+        &self,
+        _state: &SessionState,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        _limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>, DataFusionError> {
+        let projected_schema = match projection {
+            Some(p) => Arc::new(self.schema.project(p)?),
+            None => self.schema.clone(),
+        };
+
+        let kept = self.prune_partitions(filters);
+        debug!(
+            "arrow listing scan: {}/{} partition(s) survive predicate pruning",
+            kept.len(),
+            self.partitions.len()
+        );
+
+        Ok(Arc::new(ArrowListingExec {
+            schema: projected_schema,
+            projection: projection.cloned(),
+            object_store: self.object_store.clone(),
+            partitions: kept.iter().map(|&i| self.partitions[i].clone()).collect(),
+            partition_values: kept
+                .iter()
+                .map(|&i| self.partition_values[i].clone())
+                .collect(),
+        }))
+    }
+
+    /// Materializes `input`'s batches as a new IPC File-format partition
+    /// under the table's directory, so a later load of the table
+    /// (`to_mem_table`, or a `ListingArrowTable` built from a fresh listing)
+    /// picks it up. This provider's own partition list is fixed when it is
+    /// built, so its `scan()` does not see the new file. Only append
+    /// semantics are supported: a plain `INSERT INTO t SELECT ...` adds a
+    /// partition alongside the existing ones.
+    ///
+    /// Hive-partitioned tables (`self.partition_columns` non-empty) are
+    /// rejected outright: `self.schema` carries the derived partition
+    /// column(s) that a normal query's output never includes, and even past
+    /// a schema check, writing a flat file under the table root without a
+    /// `key=value/` directory would poison the next load with "partition
+    /// column not found in path". Supporting this would mean deriving or
+    /// requiring partition values per incoming batch and writing under the
+    /// matching subdirectory, which this provider doesn't do yet.
+    async fn insert_into(
+        &self,
+        _state: &SessionState,
+        input: Arc<dyn ExecutionPlan>,
+        overwrite: bool,
+    ) -> Result<Arc<dyn ExecutionPlan>, DataFusionError> {
+        if overwrite {
+            return Err(DataFusionError::NotImplemented(
+                "overwrite INSERT INTO is not supported for arrow tables, only append".to_string(),
+            ));
+        }
+
+        if !self.partition_columns.is_empty() {
+            return Err(DataFusionError::NotImplemented(format!(
+                "INSERT INTO is not supported for Hive-partitioned arrow tables (partition columns: {:?})",
+                self.partition_columns.iter().map(|(name, _)| name).collect::<Vec<_>>()
+            )));
+        }
+
+        if input.schema() != self.schema {
+            return Err(DataFusionError::Plan(format!(
+                "insert schema {:?} does not match table schema {:?}",
+                input.schema(),
+                self.schema
+            )));
+        }
+
+        Ok(Arc::new(ArrowInsertExec {
+            input,
+            table_schema: self.schema.clone(),
+            object_store: self.object_store.clone(),
+            table_location: self.location.clone(),
+        }))
+    }
+}
+
+/// Drains `input`'s batches and writes them out as a single new IPC
+/// File-format partition, mirroring the shape of DataFusion's
+/// `MemoryExec`-backed `MemTable::insert_into`: a single output `RecordBatch`
+/// with a `count` column reporting how many rows were written.
+#[derive(Debug)]
+struct ArrowInsertExec {
+    input: Arc<dyn ExecutionPlan>,
+    table_schema: SchemaRef,
+    object_store: Arc<dyn ObjectStore>,
+    table_location: object_store::path::Path,
+}
+
+fn insert_count_schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![Field::new("count", DataType::UInt64, false)]))
+}
+
+impl DisplayAs for ArrowInsertExec {
+    fn fmt_as(&self, _t: DisplayFormatType, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "ArrowInsertExec")
+    }
+}
+
+impl ExecutionPlan for ArrowInsertExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        insert_count_schema()
+    }
+
+    fn output_partitioning(&self) -> Partitioning {
+        Partitioning::UnknownPartitioning(1)
+    }
+
+    fn output_ordering(&self) -> Option<&[datafusion::physical_expr::PhysicalSortExpr]> {
+        None
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        vec![self.input.clone()]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        mut children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>, DataFusionError> {
+        Ok(Arc::new(ArrowInsertExec {
+            input: children.remove(0),
+            table_schema: self.table_schema.clone(),
+            object_store: self.object_store.clone(),
+            table_location: self.table_location.clone(),
+        }))
+    }
+
+    fn execute(
+        &self,
+        _partition: usize,
+        context: Arc<datafusion::execution::context::TaskContext>,
+    ) -> Result<SendableRecordBatchStream, DataFusionError> {
+        let input = self.input.clone();
+        let table_schema = self.table_schema.clone();
+        let object_store = self.object_store.clone();
+        let table_location = self.table_location.clone();
+        let out_schema = insert_count_schema();
+
+        let fut = async move {
+            // output_partitioning() above always reports a single partition,
+            // so DataFusion only ever drives this exec via execute(0, ..).
+            // That doesn't mean `input` itself has one partition -- drain
+            // every one of its partitions here, the same way MemTable's own
+            // insert_into sink does, or rows from partitions 1..N would be
+            // silently dropped.
+            let mut batches = Vec::new();
+            for p in 0..input.output_partitioning().partition_count() {
+                let mut input_stream = input.execute(p, context.clone())?;
+                while let Some(batch) = input_stream.try_next().await? {
+                    batches.push(batch);
+                }
+            }
+            let count: u64 = batches.iter().map(|b| b.num_rows() as u64).sum();
+
+            write_arrow_partition(&object_store, &table_location, &table_schema, &batches)
+                .await
+                .map_err(|e| DataFusionError::External(Box::new(e)))?;
+
+            RecordBatch::try_new(
+                out_schema.clone(),
+                vec![Arc::new(datafusion::arrow::array::UInt64Array::from(vec![
+                    count,
+                ]))],
+            )
+            .map_err(DataFusionError::ArrowError)
+        };
+
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            out_schema,
+            stream::once(fut),
+        )))
+    }
+
+    fn statistics(&self) -> Statistics {
+        Statistics::default()
+    }
+}
+
+/// Encodes `batches` as a single IPC File-format partition and writes it to
+/// a timestamped path under `table_location`.
+async fn write_arrow_partition(
+    object_store: &Arc<dyn ObjectStore>,
+    table_location: &object_store::path::Path,
+    schema: &SchemaRef,
+    batches: &[RecordBatch],
+) -> Result<(), ColumnQError> {
+    let mut buf = Vec::new();
+    {
+        let mut writer = arrow::ipc::writer::FileWriter::try_new(&mut buf, schema)?;
+        for batch in batches {
+            writer.write(batch)?;
+        }
+        writer.finish()?;
+    }
+
+    let written_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    let part_path = table_location.child(format!("insert-{written_at}.arrow"));
+
+    debug!("writing arrow insert partition to {part_path}...");
+    object_store.put(&part_path, buf.into()).await?;
+
+    Ok(())
+}
+
+/// Returns `false` only when `filter` is an equality predicate on a
+/// partition column whose literal disagrees with `values` — i.e. the
+/// partition is provably excluded without reading any bytes. Any other shape
+/// (data column reference, non-equality operator, etc.) is treated as
+/// "can't tell", so the partition is conservatively kept.
+fn partition_filter_admits(filter: &Expr, values: &[(String, ScalarValue)]) -> bool {
+    if let Expr::BinaryExpr(BinaryExpr { left, op, right }) = filter {
+        if *op != Operator::Eq {
+            return true;
+        }
+        let (col, lit) = match (left.as_ref(), right.as_ref()) {
+            (Expr::Column(c), Expr::Literal(l)) => (c, l),
+            (Expr::Literal(l), Expr::Column(c)) => (c, l),
+            _ => return true,
+        };
+        if let Some((_, v)) = values.iter().find(|(name, _)| name == &col.name) {
+            return v == lit;
+        }
+    }
+    true
+}
+
+/// Trailing bytes of an IPC File-format footer: a 4-byte little-endian
+/// footer length, followed by the 6-byte magic re-printed at EOF.
+const FOOTER_TRAILER_LEN: usize = 4 + ARROW_FILE_MAGIC.len();
+
+/// Reads just enough of `meta` to recover its schema via ranged requests:
+/// the IPC File format's footer (off the tail) or the streaming format's
+/// leading schema message (off the head). Each probe window is at most
+/// 64 KiB, so an object smaller than that is fetched whole, but resolving a
+/// `ListingArrowTable`'s schema never downloads a large partition in full.
+async fn read_ipc_schema_ranged(
+    object_store: &Arc<dyn ObjectStore>,
+    meta: &ObjectMeta,
+) -> Result<Schema, ColumnQError> {
+    let size = meta.size as usize;
+    let probe_len = ARROW_FILE_MAGIC.len().min(size);
+    let head = object_store
+        .get_range(&meta.location, 0..probe_len)
+        .await?;
+
+    if head.as_ref() == ARROW_FILE_MAGIC {
+        read_file_footer_schema(object_store, meta, size).await
+    } else {
+        debug!("arrow file magic not found, probing IPC stream header instead...");
+        const STREAM_HEADER_PROBE: usize = 64 * 1024;
+        let prefix_len = STREAM_HEADER_PROBE.min(size);
+        let prefix = object_store
+            .get_range(&meta.location, 0..prefix_len)
+            .await?;
+        let mut cursor = Cursor::new(prefix.as_ref());
+        let reader = arrow::ipc::reader::StreamReader::try_new(&mut cursor, None)?;
+        Ok((*reader.schema()).clone())
+    }
+}
+
+/// Reads an IPC File-format footer via ranged reads off the tail of the
+/// object: a generous initial window first, covering the common case in one
+/// round trip, falling back to a second, precisely-sized request only when
+/// the footer turns out to be larger than that window.
+async fn read_file_footer_schema(
+    object_store: &Arc<dyn ObjectStore>,
+    meta: &ObjectMeta,
+    size: usize,
+) -> Result<Schema, ColumnQError> {
+    const INITIAL_WINDOW: usize = 64 * 1024;
+    let window = INITIAL_WINDOW.min(size);
+    let tail = object_store
+        .get_range(&meta.location, (size - window)..size)
+        .await?;
+
+    if tail.len() < FOOTER_TRAILER_LEN {
+        return Err(ColumnQError::InvalidTableSchema(format!(
+            "arrow IPC file too small to contain a footer: {}",
+            meta.location
+        )));
+    }
+    let trailer = &tail[tail.len() - FOOTER_TRAILER_LEN..];
+    let footer_len = i32::from_le_bytes(trailer[..4].try_into().unwrap()) as usize;
+
+    let footer_bytes = if footer_len + FOOTER_TRAILER_LEN <= tail.len() {
+        tail.slice(tail.len() - FOOTER_TRAILER_LEN - footer_len..tail.len() - FOOTER_TRAILER_LEN)
+    } else {
+        let footer_start = size - FOOTER_TRAILER_LEN - footer_len;
+        let footer_end = size - FOOTER_TRAILER_LEN;
+        object_store
+            .get_range(&meta.location, footer_start..footer_end)
+            .await?
+    };
+
+    let footer = arrow::ipc::root_as_footer(footer_bytes.as_ref()).map_err(|e| {
+        ColumnQError::InvalidTableSchema(format!("invalid arrow IPC footer: {e:?}"))
+    })?;
+    let fb_schema = footer.schema().ok_or_else(|| {
+        ColumnQError::InvalidTableSchema("arrow IPC footer missing schema".to_string())
+    })?;
+    Ok(arrow::ipc::convert::fb_to_schema(fb_schema))
+}
+
+/// Streams `RecordBatch`es out of each partition one at a time, fetching a
+/// partition from the `ObjectStore` only once execution reaches it.
+#[derive(Debug)]
+struct ArrowListingExec {
+    schema: SchemaRef,
+    projection: Option<Vec<usize>>,
+    object_store: Arc<dyn ObjectStore>,
+    partitions: Vec<ObjectMeta>,
+    partition_values: Vec<Vec<(String, ScalarValue)>>,
+}
+
+impl DisplayAs for ArrowListingExec {
+    fn fmt_as(&self, _t: DisplayFormatType, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "ArrowListingExec: partitions={}",
+            self.partitions.len()
+        )
+    }
+}
+
+impl ExecutionPlan for ArrowListingExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn output_partitioning(&self) -> Partitioning {
+        Partitioning::UnknownPartitioning(self.partitions.len())
+    }
+
+    fn output_ordering(&self) -> Option<&[datafusion::physical_expr::PhysicalSortExpr]> {
+        None
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        vec![]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        _children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>, DataFusionError> {
+        Ok(self)
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        _context: Arc<datafusion::execution::context::TaskContext>,
+    ) -> Result<SendableRecordBatchStream, DataFusionError> {
+        let meta = self.partitions[partition].clone();
+        let partition_values = self.partition_values[partition].clone();
+        let object_store = self.object_store.clone();
+        let schema = self.schema.clone();
+        let projection = self.projection.clone();
+
+        let batch_stream = stream::once(async move {
+            let bytes = object_store.get(&meta.location).await?.bytes().await?;
+            decode_ipc_batches(&bytes, &partition_values, projection.as_deref())
+                .map(|batches| stream::iter(batches.into_iter().map(Ok)))
+                .map_err(|e| DataFusionError::External(Box::new(e)))
+        })
+        .try_flatten();
+
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            schema,
+            batch_stream,
+        )))
+    }
+
+    fn statistics(&self) -> Statistics {
+        Statistics::default()
+    }
+}
+
+fn decode_ipc_batches(
+    bytes: &[u8],
+    partition_values: &[(String, ScalarValue)],
+    projection: Option<&[usize]>,
+) -> Result<Vec<RecordBatch>, ColumnQError> {
+    let mut cursor = Cursor::new(bytes);
+    let batches: Vec<RecordBatch> = if bytes.len() >= 6 && &bytes[..6] == ARROW_FILE_MAGIC {
+        let reader = arrow::ipc::reader::FileReader::try_new(&mut cursor, None)?;
+        reader
+            .into_iter()
+            .map(|batch| Ok(batch?))
+            .collect::<Result<Vec<RecordBatch>, ColumnQError>>()?
+    } else {
+        let reader = arrow::ipc::reader::StreamReader::try_new(&mut cursor, None)?;
+        reader
+            .into_iter()
+            .map(|batch| Ok(batch?))
+            .collect::<Result<Vec<RecordBatch>, ColumnQError>>()?
+    };
+
+    let batches = if partition_values.is_empty() {
+        batches
+    } else {
+        batches
+            .iter()
+            .map(|b| append_partition_columns(b, partition_values))
+            .collect::<Result<Vec<RecordBatch>, ColumnQError>>()?
+    };
+
+    match projection {
+        Some(p) => batches
+            .into_iter()
+            .map(|b| Ok(b.project(p)?))
+            .collect::<Result<Vec<RecordBatch>, ColumnQError>>(),
+        None => Ok(batches),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -80,7 +939,7 @@ mod tests {
                 "uk_cities".to_string(),
                 tmp_dir_path.to_string_lossy().to_string(),
             )
-            .with_option(TableLoadOption::arrow {}),
+            .with_option(TableLoadOption::arrow { lazy: false }),
             &ctx,
         )
         .await?;
@@ -91,6 +950,46 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn load_partitions_lazy_uses_listing_table() -> anyhow::Result<()> {
+        use futures::TryStreamExt;
+
+        let ctx = SessionContext::new();
+        let tmp_dir = Builder::new()
+            .prefix("columnq.test.arrows_partitions_lazy")
+            .tempdir()?;
+        let tmp_dir_path = tmp_dir.path();
+
+        let source_path = test_data_path("uk_cities_with_headers.arrow");
+        assert!(fs::copy(&source_path, tmp_dir_path.join("2020-01-01.arrow"))? > 0);
+        assert!(fs::copy(&source_path, tmp_dir_path.join("2020-01-02.arrow"))? > 0);
+
+        let t = to_mem_table(
+            &TableSource::new(
+                "uk_cities".to_string(),
+                tmp_dir_path.to_string_lossy().to_string(),
+            )
+            .with_option(TableLoadOption::arrow { lazy: true }),
+            &ctx,
+        )
+        .await?;
+
+        assert!(t.as_any().downcast_ref::<ListingArrowTable>().is_some());
+
+        let plan = t.scan(&ctx.state(), None, &[], None).await?;
+        let task_ctx = ctx.task_ctx();
+        let mut total_rows = 0;
+        for p in 0..plan.output_partitioning().partition_count() {
+            let mut stream = plan.execute(p, task_ctx.clone())?;
+            while let Some(batch) = stream.try_next().await? {
+                total_rows += batch.num_rows();
+            }
+        }
+        assert_eq!(total_rows, 37 * 2);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn load_file() -> anyhow::Result<()> {
         let ctx = SessionContext::new();
@@ -103,4 +1002,476 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn load_stream_file() -> anyhow::Result<()> {
+        use datafusion::arrow::array::Int64Array;
+        use datafusion::arrow::datatypes::{DataType, Field};
+
+        let ctx = SessionContext::new();
+        let tmp_dir = Builder::new().prefix("columnq.test.arrow_stream").tempdir()?;
+        let test_path = tmp_dir.path().join("nums.arrows");
+
+        let schema = Arc::new(Schema::new(vec![Field::new("n", DataType::Int64, false)]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int64Array::from(vec![1, 2, 3, 4, 5]))],
+        )?;
+
+        {
+            let file = fs::File::create(&test_path)?;
+            let mut writer = arrow::ipc::writer::StreamWriter::try_new(file, &schema)?;
+            writer.write(&batch)?;
+            writer.finish()?;
+        }
+
+        let t = to_mem_table(
+            &TableSource::new("nums".to_string(), test_path.to_string_lossy().to_string()),
+            &ctx,
+        )
+        .await?;
+
+        let stats = t.scan(&ctx.state(), None, &[], None).await?.statistics();
+        assert_eq!(stats.num_rows, Some(5));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn load_listing_table() -> anyhow::Result<()> {
+        use futures::TryStreamExt;
+        use object_store::local::LocalFileSystem;
+
+        let ctx = SessionContext::new();
+        let tmp_dir = Builder::new()
+            .prefix("columnq.test.arrow_listing")
+            .tempdir()?;
+        let tmp_dir_path = tmp_dir.path();
+
+        let source_path = test_data_path("uk_cities_with_headers.arrow");
+        assert!(fs::copy(&source_path, tmp_dir_path.join("2020-01-01.arrow"))? > 0);
+        assert!(fs::copy(&source_path, tmp_dir_path.join("2020-01-02.arrow"))? > 0);
+
+        let object_store: Arc<dyn object_store::ObjectStore> =
+            Arc::new(LocalFileSystem::new_with_prefix(tmp_dir_path)?);
+        let partitions: Vec<_> = object_store
+            .list(None)
+            .map_ok(|meta| meta)
+            .try_collect()
+            .await?;
+
+        let t = ListingArrowTable::try_new(
+            &TableSource::new(
+                "uk_cities".to_string(),
+                tmp_dir_path.to_string_lossy().to_string(),
+            ),
+            object_store,
+            partitions,
+        )
+        .await?;
+
+        let plan = t.scan(&ctx.state(), None, &[], None).await?;
+        let task_ctx = ctx.task_ctx();
+        let mut total_rows = 0;
+        for p in 0..plan.output_partitioning().partition_count() {
+            let mut stream = plan.execute(p, task_ctx.clone())?;
+            while let Some(batch) = stream.try_next().await? {
+                total_rows += batch.num_rows();
+            }
+        }
+        assert_eq!(total_rows, 37 * 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn load_hive_partitions() -> anyhow::Result<()> {
+        let ctx = SessionContext::new();
+        let tmp_dir = Builder::new()
+            .prefix("columnq.test.arrow_hive_partitions")
+            .tempdir()?;
+        let tmp_dir_path = tmp_dir.path();
+
+        let source_path = test_data_path("uk_cities_with_headers.arrow");
+        for date in ["2020-01-01", "2020-01-02", "2020-01-03"] {
+            let partition_dir = tmp_dir_path.join(format!("date={date}"));
+            fs::create_dir_all(&partition_dir)?;
+            assert!(fs::copy(&source_path, partition_dir.join("part.arrow"))? > 0);
+        }
+
+        let t = TableSource {
+            partition_columns: Some(vec![("date".to_string(), DataType::Utf8)]),
+            ..TableSource::new(
+                "uk_cities".to_string(),
+                tmp_dir_path.to_string_lossy().to_string(),
+            )
+        };
+
+        let mem_table = to_mem_table(&t, &ctx).await?;
+        assert!(mem_table.schema().field_with_name("date").is_ok());
+
+        let stats = mem_table
+            .scan(&ctx.state(), None, &[], None)
+            .await?
+            .statistics();
+        assert_eq!(stats.num_rows, Some(37 * 3));
+
+        Ok(())
+    }
+
+    #[test]
+    fn partition_filter_admits_prunes_mismatched_equality() {
+        let values = vec![("date".to_string(), ScalarValue::Utf8(Some("2020-01-02".to_string())))];
+
+        let matching = Expr::Column(datafusion::common::Column::from_name("date")).eq(
+            Expr::Literal(ScalarValue::Utf8(Some("2020-01-02".to_string()))),
+        );
+        assert!(partition_filter_admits(&matching, &values));
+
+        let mismatching = Expr::Column(datafusion::common::Column::from_name("date")).eq(
+            Expr::Literal(ScalarValue::Utf8(Some("2020-01-01".to_string()))),
+        );
+        assert!(!partition_filter_admits(&mismatching, &values));
+    }
+
+    #[tokio::test]
+    async fn scan_with_no_surviving_partitions_yields_zero_rows() -> anyhow::Result<()> {
+        use futures::TryStreamExt;
+
+        let ctx = SessionContext::new();
+        let tmp_dir = Builder::new()
+            .prefix("columnq.test.arrow_listing_empty")
+            .tempdir()?;
+        let tmp_dir_path = tmp_dir.path();
+
+        let source_path = test_data_path("uk_cities_with_headers.arrow");
+        let partition_dir = tmp_dir_path.join("date=2020-01-01");
+        fs::create_dir_all(&partition_dir)?;
+        fs::copy(&source_path, partition_dir.join("part.arrow"))?;
+
+        let object_store: Arc<dyn object_store::ObjectStore> =
+            Arc::new(object_store::local::LocalFileSystem::new_with_prefix(tmp_dir_path)?);
+        let partitions: Vec<_> = object_store.list(None).try_collect().await?;
+
+        let t = ListingArrowTable::try_new(
+            &TableSource {
+                partition_columns: Some(vec![("date".to_string(), DataType::Utf8)]),
+                ..TableSource::new(
+                    "uk_cities".to_string(),
+                    tmp_dir_path.to_string_lossy().to_string(),
+                )
+            },
+            object_store,
+            partitions,
+        )
+        .await?;
+
+        // an equality filter on the partition column that no partition can
+        // satisfy: `prune_partitions` should leave zero surviving
+        // partitions. A previous version still reported
+        // `output_partitioning() == 1` via `.max(1)`, so DataFusion would
+        // call `execute(0, ..)` and panic indexing the empty `partitions`
+        // Vec; this should instead report zero partitions and never be
+        // executed.
+        let filters = [Expr::Column(datafusion::common::Column::from_name("date")).eq(
+            Expr::Literal(ScalarValue::Utf8(Some("2099-01-01".to_string()))),
+        )];
+        let plan = t.scan(&ctx.state(), None, &filters, None).await?;
+        assert_eq!(plan.output_partitioning().partition_count(), 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn concurrent_partition_loading_matches_sequential_row_counts() -> anyhow::Result<()> {
+        let ctx = SessionContext::new();
+        let tmp_dir = Builder::new()
+            .prefix("columnq.test.arrow_many_partitions")
+            .tempdir()?;
+        let tmp_dir_path = tmp_dir.path();
+
+        let source_path = test_data_path("uk_cities_with_headers.arrow");
+        const NUM_PARTITIONS: usize = 32;
+        for i in 0..NUM_PARTITIONS {
+            assert!(
+                fs::copy(&source_path, tmp_dir_path.join(format!("part-{i:03}.arrow")))? > 0
+            );
+        }
+
+        let sequential = to_mem_table(
+            &TableSource::new(
+                "uk_cities".to_string(),
+                tmp_dir_path.to_string_lossy().to_string(),
+            ),
+            &ctx,
+        )
+        .await?;
+        let sequential_rows = sequential
+            .scan(&ctx.state(), None, &[], None)
+            .await?
+            .statistics()
+            .num_rows;
+
+        let concurrent = to_mem_table(
+            &TableSource {
+                load_concurrency: Some(8),
+                ..TableSource::new(
+                    "uk_cities".to_string(),
+                    tmp_dir_path.to_string_lossy().to_string(),
+                )
+            },
+            &ctx,
+        )
+        .await?;
+        let concurrent_rows = concurrent
+            .scan(&ctx.state(), None, &[], None)
+            .await?
+            .statistics()
+            .num_rows;
+
+        assert_eq!(sequential_rows, Some(37 * NUM_PARTITIONS));
+        assert_eq!(concurrent_rows, sequential_rows);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn insert_into_writes_new_partition() -> anyhow::Result<()> {
+        use datafusion::arrow::array::Int64Array;
+        use datafusion::arrow::datatypes::Field;
+        use datafusion::physical_plan::memory::MemoryExec;
+        use futures::TryStreamExt;
+        use object_store::local::LocalFileSystem;
+
+        let ctx = SessionContext::new();
+        let tmp_dir = Builder::new()
+            .prefix("columnq.test.arrow_insert_into")
+            .tempdir()?;
+        let tmp_dir_path = tmp_dir.path();
+
+        let schema = Arc::new(Schema::new(vec![Field::new("n", DataType::Int64, false)]));
+        {
+            let file = fs::File::create(tmp_dir_path.join("part-000.arrow"))?;
+            let mut writer = arrow::ipc::writer::FileWriter::try_new(file, &schema)?;
+            writer.write(&RecordBatch::try_new(
+                schema.clone(),
+                vec![Arc::new(Int64Array::from(vec![1, 2, 3]))],
+            )?)?;
+            writer.finish()?;
+        }
+
+        let object_store: Arc<dyn object_store::ObjectStore> =
+            Arc::new(LocalFileSystem::new_with_prefix(tmp_dir_path)?);
+        let partitions: Vec<_> = object_store
+            .list(None)
+            .try_collect()
+            .await?;
+
+        let t = ListingArrowTable::try_new(
+            &TableSource::new(
+                "nums".to_string(),
+                tmp_dir_path.to_string_lossy().to_string(),
+            ),
+            object_store.clone(),
+            partitions,
+        )
+        .await?;
+
+        let input_batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int64Array::from(vec![4, 5]))],
+        )?;
+        let input: Arc<dyn ExecutionPlan> =
+            Arc::new(MemoryExec::try_new(&[vec![input_batch]], schema.clone(), None)?);
+
+        let insert_plan = t.insert_into(&ctx.state(), input, false).await?;
+        let task_ctx = ctx.task_ctx();
+        let mut result_stream = insert_plan.execute(0, task_ctx)?;
+        let count_batch = result_stream.try_next().await?.expect("count batch");
+        let counts = count_batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<datafusion::arrow::array::UInt64Array>()
+            .unwrap();
+        assert_eq!(counts.value(0), 2);
+
+        // the new partition is picked up by re-listing the table directory.
+        let partitions_after: Vec<_> = object_store.list(None).try_collect().await?;
+        assert_eq!(partitions_after.len(), 2);
+
+        let t_after = ListingArrowTable::try_new(
+            &TableSource::new(
+                "nums".to_string(),
+                tmp_dir_path.to_string_lossy().to_string(),
+            ),
+            object_store,
+            partitions_after,
+        )
+        .await?;
+        let plan = t_after.scan(&ctx.state(), None, &[], None).await?;
+        let task_ctx = ctx.task_ctx();
+        let mut total_rows = 0;
+        for p in 0..plan.output_partitioning().partition_count() {
+            let mut stream = plan.execute(p, task_ctx.clone())?;
+            while let Some(batch) = stream.try_next().await? {
+                total_rows += batch.num_rows();
+            }
+        }
+        assert_eq!(total_rows, 5);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn insert_into_drains_all_input_partitions() -> anyhow::Result<()> {
+        use datafusion::arrow::array::Int64Array;
+        use datafusion::arrow::datatypes::Field;
+        use datafusion::physical_plan::memory::MemoryExec;
+        use futures::TryStreamExt;
+        use object_store::local::LocalFileSystem;
+
+        let ctx = SessionContext::new();
+        let tmp_dir = Builder::new()
+            .prefix("columnq.test.arrow_insert_into_multi_partition")
+            .tempdir()?;
+        let tmp_dir_path = tmp_dir.path();
+
+        let schema = Arc::new(Schema::new(vec![Field::new("n", DataType::Int64, false)]));
+        {
+            let file = fs::File::create(tmp_dir_path.join("part-000.arrow"))?;
+            let mut writer = arrow::ipc::writer::FileWriter::try_new(file, &schema)?;
+            writer.write(&RecordBatch::try_new(
+                schema.clone(),
+                vec![Arc::new(Int64Array::from(vec![1, 2, 3]))],
+            )?)?;
+            writer.finish()?;
+        }
+
+        let object_store: Arc<dyn object_store::ObjectStore> =
+            Arc::new(LocalFileSystem::new_with_prefix(tmp_dir_path)?);
+        let partitions: Vec<_> = object_store.list(None).try_collect().await?;
+
+        let t = ListingArrowTable::try_new(
+            &TableSource::new(
+                "nums".to_string(),
+                tmp_dir_path.to_string_lossy().to_string(),
+            ),
+            object_store.clone(),
+            partitions,
+        )
+        .await?;
+
+        // a source plan with two output partitions, e.g. what
+        // `ArrowListingExec` itself produces for a multi-file table -- a
+        // previous version of `ArrowInsertExec::execute` only drained
+        // partition 0 of the input, silently dropping partition 1's rows.
+        let batch_a = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int64Array::from(vec![4, 5]))],
+        )?;
+        let batch_b = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int64Array::from(vec![6, 7, 8]))],
+        )?;
+        let input: Arc<dyn ExecutionPlan> = Arc::new(MemoryExec::try_new(
+            &[vec![batch_a], vec![batch_b]],
+            schema.clone(),
+            None,
+        )?);
+        assert_eq!(input.output_partitioning().partition_count(), 2);
+
+        let insert_plan = t.insert_into(&ctx.state(), input, false).await?;
+        let task_ctx = ctx.task_ctx();
+        let mut result_stream = insert_plan.execute(0, task_ctx)?;
+        let count_batch = result_stream.try_next().await?.expect("count batch");
+        let counts = count_batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<datafusion::arrow::array::UInt64Array>()
+            .unwrap();
+        assert_eq!(counts.value(0), 5);
+
+        let partitions_after: Vec<_> = object_store.list(None).try_collect().await?;
+        let t_after = ListingArrowTable::try_new(
+            &TableSource::new(
+                "nums".to_string(),
+                tmp_dir_path.to_string_lossy().to_string(),
+            ),
+            object_store,
+            partitions_after,
+        )
+        .await?;
+        let plan = t_after.scan(&ctx.state(), None, &[], None).await?;
+        let task_ctx = ctx.task_ctx();
+        let mut total_rows = 0;
+        for p in 0..plan.output_partitioning().partition_count() {
+            let mut stream = plan.execute(p, task_ctx.clone())?;
+            while let Some(batch) = stream.try_next().await? {
+                total_rows += batch.num_rows();
+            }
+        }
+        assert_eq!(total_rows, 3 + 5);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn insert_into_rejects_hive_partitioned_table() -> anyhow::Result<()> {
+        use datafusion::arrow::array::Int64Array;
+        use datafusion::arrow::datatypes::Field;
+        use datafusion::physical_plan::memory::MemoryExec;
+        use futures::TryStreamExt;
+        use object_store::local::LocalFileSystem;
+
+        let ctx = SessionContext::new();
+        let tmp_dir = Builder::new()
+            .prefix("columnq.test.arrow_insert_into_partitioned")
+            .tempdir()?;
+        let tmp_dir_path = tmp_dir.path();
+
+        let data_schema = Arc::new(Schema::new(vec![Field::new("n", DataType::Int64, false)]));
+        let partition_dir = tmp_dir_path.join("date=2020-01-01");
+        fs::create_dir_all(&partition_dir)?;
+        {
+            let file = fs::File::create(partition_dir.join("part.arrow"))?;
+            let mut writer = arrow::ipc::writer::FileWriter::try_new(file, &data_schema)?;
+            writer.write(&RecordBatch::try_new(
+                data_schema.clone(),
+                vec![Arc::new(Int64Array::from(vec![1, 2, 3]))],
+            )?)?;
+            writer.finish()?;
+        }
+
+        let object_store: Arc<dyn object_store::ObjectStore> =
+            Arc::new(LocalFileSystem::new_with_prefix(tmp_dir_path)?);
+        let partitions: Vec<_> = object_store.list(None).try_collect().await?;
+
+        let t = ListingArrowTable::try_new(
+            &TableSource {
+                partition_columns: Some(vec![("date".to_string(), DataType::Utf8)]),
+                ..TableSource::new(
+                    "nums".to_string(),
+                    tmp_dir_path.to_string_lossy().to_string(),
+                )
+            },
+            object_store,
+            partitions,
+        )
+        .await?;
+
+        let input_batch = RecordBatch::try_new(
+            data_schema.clone(),
+            vec![Arc::new(Int64Array::from(vec![4, 5]))],
+        )?;
+        let input: Arc<dyn ExecutionPlan> =
+            Arc::new(MemoryExec::try_new(&[vec![input_batch]], data_schema, None)?);
+
+        let err = t
+            .insert_into(&ctx.state(), input, false)
+            .await
+            .expect_err("insert_into on a Hive-partitioned table should be rejected");
+        assert!(err.to_string().contains("Hive-partitioned"));
+
+        Ok(())
+    }
 }
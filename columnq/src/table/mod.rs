@@ -0,0 +1,92 @@
+pub mod arrow_ipc_file;
+
+use datafusion::arrow::datatypes::{DataType, Schema};
+
+use crate::error::ColumnQError;
+
+/// Format-specific knobs for loading a [`TableSource`]. Each variant carries
+/// only the options meaningful for that format.
+#[derive(Debug, Clone, PartialEq)]
+#[allow(non_camel_case_types)]
+pub enum TableLoadOption {
+    arrow {
+        /// When set, load through `arrow_ipc_file::ListingArrowTable`
+        /// instead of eagerly decoding every partition into a `MemTable`.
+        /// Intended for large or remote partition sets.
+        lazy: bool,
+    },
+}
+
+/// Describes one table to be registered with a `SessionContext`: where its
+/// data lives, how to load it, and an optional pre-declared schema.
+#[derive(Debug, Clone, Default)]
+pub struct TableSource {
+    pub name: String,
+    location: String,
+    pub schema: Option<Schema>,
+    pub option: Option<TableLoadOption>,
+    /// Hive-style `key=value` path segments to derive as constant-valued
+    /// columns, e.g. `date` for partitions laid out as
+    /// `date=2020-01-02/part.arrow`. `None` means the source has no Hive
+    /// partitioning and no columns are derived from its paths. The default
+    /// eager loader reads only a directory's top-level files; the
+    /// `load_concurrency` and lazy loaders walk it recursively.
+    pub partition_columns: Option<Vec<(String, DataType)>>,
+    /// Bound on in-flight partition decodes for the concurrent loader
+    /// (`arrow_ipc_file::to_mem_table_concurrent`), which setting this
+    /// selects; that loader uses the available parallelism when it is
+    /// `None`. With this and `partition_columns` both `None`, partitions
+    /// are decoded one after another by the default loader.
+    pub load_concurrency: Option<usize>,
+}
+
+impl TableSource {
+    pub fn new(name: String, location: String) -> Self {
+        Self {
+            name,
+            location,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_option(mut self, option: TableLoadOption) -> Self {
+        self.option = Some(option);
+        self
+    }
+
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+}
+
+/// Reads every partition `t` resolves to off the local filesystem, decoding
+/// each with `$per_partition` (a `FnOnce(std::fs::File) -> Result<T,
+/// ColumnQError>`). `t.location()` may point at a single file or a directory
+/// of files, which are visited in sorted order for reproducible results.
+/// `$dfctx` is accepted for parity with loaders that need a `SessionContext`
+/// to resolve remote sources, but is unused for the local path.
+#[macro_export]
+macro_rules! partitions_from_table_source {
+    ($source:expr, $per_partition:expr, $dfctx:expr) => {{
+        let _ = &$dfctx;
+        (|| -> Result<Vec<_>, $crate::error::ColumnQError> {
+            let base = std::path::PathBuf::from($source.location());
+            let mut paths = if base.is_dir() {
+                std::fs::read_dir(&base)?
+                    .map(|entry| Ok(entry?.path()))
+                    .collect::<Result<Vec<std::path::PathBuf>, $crate::error::ColumnQError>>()?
+            } else {
+                vec![base]
+            };
+            paths.sort();
+
+            paths
+                .into_iter()
+                .map(|path| {
+                    let file = std::fs::File::open(&path)?;
+                    ($per_partition)(file)
+                })
+                .collect()
+        })()
+    }};
+}
@@ -0,0 +1,10 @@
+use std::path::PathBuf;
+
+/// Resolves a fixture under `columnq/test_data/` by file name.
+pub(crate) fn test_data_path(name: &str) -> String {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join(name)
+        .to_string_lossy()
+        .to_string()
+}